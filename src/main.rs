@@ -1,6 +1,9 @@
 extern crate primal;
 
 use primal::Sieve;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
 
 trait Primality {
     fn is_prime(&self, n: u64) -> bool;
@@ -190,6 +193,434 @@ impl Primality for SievePrimality {
     }
 }
 
+struct SegmentedSieve {
+    lo: u64,
+    is_composite: Vec<bool>,
+}
+
+impl SegmentedSieve {
+    fn new(lo: u64, hi: u64) -> Self {
+        let base_limit = (hi as f64).sqrt().ceil() as u64;
+        let base_sieve = Sieve::new(base_limit as usize);
+        let base_primes: Vec<u64> = base_sieve
+            .primes_from(0)
+            .map(|p| p as u64)
+            .take_while(|&p| p <= base_limit)
+            .collect();
+
+        let mut is_composite = vec![false; (hi - lo + 1) as usize];
+
+        for p in base_primes {
+            let mut start = lo.div_ceil(p) * p;
+            if start < p * p {
+                start = p * p;
+            }
+
+            let mut idx = start;
+            while idx <= hi {
+                is_composite[(idx - lo) as usize] = true;
+                idx += p;
+            }
+        }
+
+        if lo == 0 {
+            is_composite[0] = true;
+        }
+        if lo <= 1 && hi >= 1 {
+            is_composite[(1 - lo) as usize] = true;
+        }
+
+        SegmentedSieve { lo, is_composite }
+    }
+
+    fn primes(&self) -> impl Iterator<Item = u64> + '_ {
+        self.is_composite
+            .iter()
+            .enumerate()
+            .filter(|&(_, &composite)| !composite)
+            .map(move |(i, _)| self.lo + i as u64)
+    }
+
+    fn sum(&self) -> u64 {
+        self.primes().sum()
+    }
+}
+
+fn concurrent_sieve_plan(limit: u64) -> (Arc<Vec<u64>>, u64, u64) {
+    let base_limit = (limit as f64).sqrt().ceil() as u64;
+    let base_sieve = Sieve::new(base_limit as usize);
+    let base_primes = Arc::new(
+        base_sieve
+            .primes_from(0)
+            .map(|p| p as u64)
+            .take_while(|&p| p <= base_limit)
+            .collect::<Vec<u64>>(),
+    );
+
+    let num_threads = thread::available_parallelism()
+        .map(|n| n.get() as u64)
+        .unwrap_or(1);
+    let block_size = (limit + num_threads) / num_threads;
+
+    (base_primes, num_threads, block_size)
+}
+
+fn sieve_block(lo: u64, hi: u64, base_primes: &[u64]) -> Vec<bool> {
+    let mut is_composite = vec![false; (hi - lo + 1) as usize];
+
+    for &p in base_primes {
+        let mut start = lo.div_ceil(p) * p;
+        if start < p * p {
+            start = p * p;
+        }
+
+        let mut idx = start;
+        while idx <= hi {
+            is_composite[(idx - lo) as usize] = true;
+            idx += p;
+        }
+    }
+
+    if lo == 0 {
+        is_composite[0] = true;
+    }
+    if lo <= 1 && hi >= 1 {
+        is_composite[(1 - lo) as usize] = true;
+    }
+
+    is_composite
+}
+
+struct ConcurrentSieve;
+
+impl ConcurrentSieve {
+    fn sum_primes(limit: u64) -> u64 {
+        Self::count_and_sum(limit).1
+    }
+
+    fn count_primes(limit: u64) -> u64 {
+        Self::count_and_sum(limit).0
+    }
+
+    fn count_and_sum(limit: u64) -> (u64, u64) {
+        let (base_primes, num_threads, block_size) = concurrent_sieve_plan(limit);
+        let (tx, rx) = mpsc::channel();
+
+        let mut handles = Vec::new();
+        for block in 0..num_threads {
+            let lo = block * block_size;
+            if lo > limit {
+                break;
+            }
+            let hi = ((block + 1) * block_size - 1).min(limit);
+            let base_primes = Arc::clone(&base_primes);
+            let tx = tx.clone();
+
+            handles.push(thread::spawn(move || {
+                let is_composite = sieve_block(lo, hi, &base_primes);
+
+                let (count, sum) = is_composite
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &composite)| !composite)
+                    .fold((0u64, 0u64), |(count, sum), (i, _)| {
+                        (count + 1, sum + lo + i as u64)
+                    });
+
+                tx.send((count, sum)).expect("receiver dropped before block finished");
+            }));
+        }
+        drop(tx);
+
+        for handle in handles {
+            handle.join().expect("worker thread panicked");
+        }
+
+        rx.iter()
+            .fold((0, 0), |(count_acc, sum_acc), (count, sum)| {
+                (count_acc + count, sum_acc + sum)
+            })
+    }
+
+    fn primes(limit: u64) -> Vec<u64> {
+        let (base_primes, num_threads, block_size) = concurrent_sieve_plan(limit);
+        let (tx, rx) = mpsc::channel();
+
+        let mut handles = Vec::new();
+        for block in 0..num_threads {
+            let lo = block * block_size;
+            if lo > limit {
+                break;
+            }
+            let hi = ((block + 1) * block_size - 1).min(limit);
+            let base_primes = Arc::clone(&base_primes);
+            let tx = tx.clone();
+
+            handles.push(thread::spawn(move || {
+                let is_composite = sieve_block(lo, hi, &base_primes);
+
+                let block_primes: Vec<u64> = is_composite
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &composite)| !composite)
+                    .map(|(i, _)| lo + i as u64)
+                    .collect();
+
+                tx.send((block, block_primes))
+                    .expect("receiver dropped before block finished");
+            }));
+        }
+        drop(tx);
+
+        for handle in handles {
+            handle.join().expect("worker thread panicked");
+        }
+
+        let mut blocks: Vec<(u64, Vec<u64>)> = rx.iter().collect();
+        blocks.sort_by_key(|&(block, _)| block);
+        blocks.into_iter().flat_map(|(_, primes)| primes).collect()
+    }
+}
+
+fn factorize(n: u64) -> Vec<(u64, u32)> {
+    let mut n = n;
+    let mut factors = Vec::new();
+
+    let limit = (n as f64).sqrt() as u64 + 1;
+    let sieve = Sieve::new(limit as usize);
+    let primes = sieve
+        .primes_from(0)
+        .map(|p| p as u64)
+        .take_while(|&p| p <= limit);
+
+    for p in primes {
+        if p * p > n {
+            break;
+        }
+
+        if n.is_multiple_of(p) {
+            let mut exponent = 0;
+            while n.is_multiple_of(p) {
+                n /= p;
+                exponent += 1;
+            }
+            factors.push((p, exponent));
+        }
+    }
+
+    if n > 1 {
+        factors.push((n, 1));
+    }
+
+    factors
+}
+
+fn unique_primes(n: u64) -> impl Iterator<Item = u64> {
+    factorize(n).into_iter().map(|(p, _)| p)
+}
+
+fn divisors(n: u64) -> Vec<u64> {
+    let mut divisors = vec![1u64];
+
+    for (p, exponent) in factorize(n) {
+        let mut next = Vec::with_capacity(divisors.len() * (exponent as usize + 1));
+        let mut power = 1u64;
+        for _ in 0..=exponent {
+            for &d in &divisors {
+                next.push(d * power);
+            }
+            power *= p;
+        }
+        divisors = next;
+    }
+
+    divisors.sort_unstable();
+    divisors
+}
+
+fn num_divisors(n: u64) -> u64 {
+    factorize(n)
+        .into_iter()
+        .map(|(_, exponent)| u64::from(exponent) + 1)
+        .product()
+}
+
+struct BitSieve {
+    cmpsts: Vec<u32>,
+}
+
+impl BitSieve {
+    fn new(limit: u64) -> Self {
+        let size = if limit < 3 { 0 } else { (limit - 3) / 2 + 1 };
+        let mut cmpsts = vec![0u32; size as usize / 32 + 1];
+
+        let sqrt_limit = (limit as f64).sqrt() as u64;
+        if sqrt_limit >= 3 {
+            let max_i = (sqrt_limit - 3) / 2;
+
+            for i in 0..=max_i {
+                if cmpsts[i as usize >> 5] & (1 << (i & 31)) != 0 {
+                    continue;
+                }
+
+                let p = 2 * i + 3;
+                let mut pos = (p * p - 3) / 2;
+                while pos < size {
+                    cmpsts[pos as usize >> 5] |= 1 << (pos & 31);
+                    pos += p;
+                }
+            }
+        }
+
+        BitSieve { cmpsts }
+    }
+}
+
+impl Primality for BitSieve {
+    fn is_prime(&self, n: u64) -> bool {
+        match n {
+            0 | 1 => false,
+            2 => true,
+            n if n & 1 == 0 => false,
+            n => {
+                let idx = ((n - 3) / 2) as usize;
+                self.cmpsts[idx >> 5] & (1 << (idx & 31)) == 0
+            }
+        }
+    }
+}
+
+fn nth_prime(n: usize) -> u64 {
+    const SMALL_PRIMES: [u64; 5] = [2, 3, 5, 7, 11];
+
+    assert!(n >= 1, "n must be >= 1");
+
+    if n <= SMALL_PRIMES.len() {
+        return SMALL_PRIMES[n - 1];
+    }
+
+    let x = n as f64;
+    let bound = (x * x.ln() + x * x.ln().ln()).ceil() as usize;
+    let sieve = Sieve::new(bound);
+
+    sieve
+        .primes_from(0)
+        .nth(n - 1)
+        .expect("analytic bound was too small for the n-th prime") as u64
+}
+
+fn prime_pi(x: u64) -> usize {
+    let sieve = Sieve::new(x as usize);
+    sieve.primes_from(0).take_while(|&p| p as u64 <= x).count()
+}
+
+struct Montgomery {
+    n: u64,
+    ni: u64,
+    r2: u64,
+}
+
+impl Montgomery {
+    fn new(n: u64) -> Self {
+        let mut ni = n;
+        for _ in 0..5 {
+            ni = ni.wrapping_mul(2u64.wrapping_sub(n.wrapping_mul(ni)));
+        }
+
+        let r = ((1u128 << 64) % u128::from(n)) as u64;
+        let r2 = ((u128::from(r) * u128::from(r)) % u128::from(n)) as u64;
+
+        Montgomery { n, ni, r2 }
+    }
+
+    fn mrmul(&self, a: u64, b: u64) -> u64 {
+        let t = u128::from(a) * u128::from(b);
+        let m = (t as u64).wrapping_mul(self.ni);
+        let u = (t + u128::from(m) * u128::from(self.n)) >> 64;
+        let u = u as u64;
+
+        if u >= self.n {
+            u - self.n
+        } else {
+            u
+        }
+    }
+
+    fn to_mont(&self, a: u64) -> u64 {
+        self.mrmul(a, self.r2)
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn from_mont(&self, a: u64) -> u64 {
+        self.mrmul(a, 1)
+    }
+
+    fn pow(&self, x: u64, mut d: u64) -> u64 {
+        let mut ret = self.to_mont(1);
+        let mut x = self.to_mont(x);
+
+        while d != 0 {
+            if d & 1 == 1 {
+                ret = self.mrmul(ret, x);
+            }
+            d >>= 1;
+            x = self.mrmul(x, x);
+        }
+
+        ret
+    }
+}
+
+struct MillerRabinPrimality;
+
+impl Primality for MillerRabinPrimality {
+    fn is_prime(&self, n: u64) -> bool {
+        const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+        if n < 2 {
+            return false;
+        }
+
+        for &p in &WITNESSES {
+            if n == p {
+                return true;
+            }
+            if n.is_multiple_of(p) {
+                return false;
+            }
+        }
+
+        let mut d = n - 1;
+        let mut s = 0;
+        while d & 1 == 0 {
+            d >>= 1;
+            s += 1;
+        }
+
+        let mont = Montgomery::new(n);
+        let mont_one = mont.to_mont(1);
+        let mont_minus_one = mont.to_mont(n - 1);
+
+        'witness: for &a in &WITNESSES {
+            let mut x = mont.pow(a, d);
+            if x == mont_one || x == mont_minus_one {
+                continue;
+            }
+
+            for _ in 0..s - 1 {
+                x = mont.mrmul(x, x);
+                if x == mont_minus_one {
+                    continue 'witness;
+                }
+            }
+
+            return false;
+        }
+
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,4 +694,130 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn segmented_sieve_matches_full_sieve() {
+        let segmented = SegmentedSieve::new(0, 100_000);
+        let sieve = SievePrimality::new(100_000);
+
+        let expected: Vec<u64> = (0..=100_000).filter(|&i| sieve.is_prime(i)).collect();
+        let actual: Vec<u64> = segmented.primes().collect();
+
+        assert_eq!(actual, expected);
+        assert_eq!(segmented.sum(), expected.iter().sum());
+    }
+
+    #[test]
+    fn segmented_sieve_handles_high_windows() {
+        let segmented = SegmentedSieve::new(1_000_000, 1_000_100);
+        let sieve = SievePrimality::new(1_000_100);
+
+        let expected: Vec<u64> = (1_000_000..=1_000_100).filter(|&i| sieve.is_prime(i)).collect();
+        let actual: Vec<u64> = segmented.primes().collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn segmented_sieve_handles_single_point_window_at_origin() {
+        let segmented = SegmentedSieve::new(0, 0);
+        assert_eq!(segmented.primes().collect::<Vec<u64>>(), Vec::new());
+    }
+
+    #[test]
+    fn montgomery_mrmul_matches_plain_modmul() {
+        let cases: [(u64, u64, u64); 5] = [
+            (12345, 6789, 1_000_000_007),
+            (u64::from(u32::MAX), u64::from(u32::MAX), 1_000_000_007),
+            (999_999_937, 999_999_929, 999_999_937),
+            (1, 1, 3),
+            (7, 5, 11),
+        ];
+
+        for (a, b, n) in cases {
+            let mont = Montgomery::new(n);
+            let expected = (u128::from(a) * u128::from(b) % u128::from(n)) as u64;
+            let actual = mont.from_mont(mont.mrmul(mont.to_mont(a), mont.to_mont(b)));
+            assert_eq!(actual, expected, "mismatch for a={}, b={}, n={}", a, b, n);
+        }
+    }
+
+    #[test]
+    fn bit_sieve_works() {
+        let bits = BitSieve::new(1000);
+        let sieve = SievePrimality::new(1000);
+
+        for i in 1..=1000 {
+            assert_eq!(
+                bits.is_prime(i),
+                sieve.is_prime(i),
+                "Incorrect for: {}", i
+            );
+        }
+    }
+
+    #[test]
+    fn nth_prime_works() {
+        assert_eq!(nth_prime(1), 2);
+        assert_eq!(nth_prime(6), 13);
+        assert_eq!(nth_prime(10001), 104743);
+    }
+
+    #[test]
+    fn prime_pi_works() {
+        assert_eq!(prime_pi(10), 4);
+        assert_eq!(prime_pi(100), 25);
+    }
+
+    #[test]
+    fn factorize_works() {
+        assert_eq!(factorize(1), Vec::new());
+        assert_eq!(factorize(13), vec![(13, 1)]);
+        assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+    }
+
+    #[test]
+    fn unique_primes_works() {
+        let primes: Vec<u64> = unique_primes(360).collect();
+        assert_eq!(primes, vec![2, 3, 5]);
+    }
+
+    #[test]
+    fn divisors_works() {
+        let mut divisors_of_28 = divisors(28);
+        divisors_of_28.sort_unstable();
+        assert_eq!(divisors_of_28, vec![1, 2, 4, 7, 14, 28]);
+    }
+
+    #[test]
+    fn num_divisors_works() {
+        assert_eq!(num_divisors(360), 24);
+        assert_eq!(num_divisors(13), 2);
+    }
+
+    #[test]
+    fn concurrent_sieve_matches_sieve_primality() {
+        let sieve = SievePrimality::new(200_000);
+
+        let expected: Vec<u64> = (0..=200_000).filter(|&i| sieve.is_prime(i)).collect();
+        let actual = ConcurrentSieve::primes(200_000);
+
+        assert_eq!(actual, expected);
+        assert_eq!(ConcurrentSieve::count_primes(200_000), expected.len() as u64);
+        assert_eq!(ConcurrentSieve::sum_primes(200_000), expected.iter().sum());
+    }
+
+    #[test]
+    fn miller_rabin_primality_works() {
+        let miller_rabin = MillerRabinPrimality;
+        let sieve = SievePrimality::new(100_000);
+
+        for i in 1..=100_000 {
+            assert_eq!(
+                miller_rabin.is_prime(i),
+                sieve.is_prime(i),
+                "Incorrect for: {}", i
+            );
+        }
+    }
 }